@@ -1,5 +1,5 @@
 use super::*;
-use delimited::Delimited;
+use delimited::{self, Delimited};
 
 ast_struct! {
     /// An enum variant.
@@ -39,6 +39,94 @@ ast_enum_of_structs! {
     }
 }
 
+impl Fields {
+    /// Returns an iterator over the fields, regardless of whether they are
+    /// named, unnamed, or absent entirely.
+    pub fn iter<'a>(&'a self) -> Iter<'a> {
+        Iter {
+            inner: self.delimited().map(Delimited::iter),
+        }
+    }
+
+    /// Returns a mutable iterator over the fields, regardless of whether
+    /// they are named, unnamed, or absent entirely.
+    pub fn iter_mut<'a>(&'a mut self) -> IterMut<'a> {
+        IterMut {
+            inner: self.delimited_mut().map(Delimited::iter_mut),
+        }
+    }
+
+    /// Returns the number of fields.
+    pub fn len(&self) -> usize {
+        self.delimited().map_or(0, Delimited::len)
+    }
+
+    /// Returns true if there are no fields.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// `Named` and `Unnamed` store their fields the same way; `Unit` has
+    /// none. Centralizing that here lets `iter`/`iter_mut`/`len` share one
+    /// concrete iterator type per variant pair instead of boxing.
+    fn delimited(&self) -> Option<&Delimited<Field, Token![,]>> {
+        match *self {
+            Fields::Named(ref fields) => Some(&fields.fields),
+            Fields::Unnamed(ref fields) => Some(&fields.fields),
+            Fields::Unit => None,
+        }
+    }
+
+    fn delimited_mut(&mut self) -> Option<&mut Delimited<Field, Token![,]>> {
+        match *self {
+            Fields::Named(ref mut fields) => Some(&mut fields.fields),
+            Fields::Unnamed(ref mut fields) => Some(&mut fields.fields),
+            Fields::Unit => None,
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a Fields {
+    type Item = &'a Field;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the fields of a [`Fields`](enum.Fields.html).
+pub struct Iter<'a> {
+    inner: Option<delimited::Iter<'a, Field, Token![,]>>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = &'a Field;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner {
+            Some(ref mut inner) => inner.next(),
+            None => None,
+        }
+    }
+}
+
+/// A mutable iterator over the fields of a [`Fields`](enum.Fields.html).
+pub struct IterMut<'a> {
+    inner: Option<delimited::IterMut<'a, Field, Token![,]>>,
+}
+
+impl<'a> Iterator for IterMut<'a> {
+    type Item = &'a mut Field;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.inner {
+            Some(ref mut inner) => inner.next(),
+            None => None,
+        }
+    }
+}
+
 ast_struct! {
     /// A field of a struct or enum variant.
     pub struct Field {
@@ -76,6 +164,8 @@ ast_enum_of_structs! {
         }),
 
         /// Restricted, e.g. `pub(self)` or `pub(super)` or `pub(in some::module)`.
+        ///
+        /// See `is_bare_path_keyword` for when `in_token` may be absent.
         pub Restricted(VisRestricted {
             pub pub_token: Token![pub],
             pub paren_token: token::Paren,
@@ -88,6 +178,102 @@ ast_enum_of_structs! {
     }
 }
 
+impl Visibility {
+    /// Returns true if this is `pub`.
+    pub fn is_public(&self) -> bool {
+        match *self {
+            Visibility::Public(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true if this is the inherited (private) visibility.
+    pub fn is_inherited(&self) -> bool {
+        match *self {
+            Visibility::Inherited => true,
+            _ => false,
+        }
+    }
+
+    /// Returns true if this is `pub(crate)`.
+    pub fn is_crate_level(&self) -> bool {
+        match *self {
+            Visibility::Crate(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns the restricting path, e.g. `self`, `super`, or `some::module`,
+    /// if this is a `Visibility::Restricted`.
+    pub fn restricted_path(&self) -> Option<&Path> {
+        match *self {
+            Visibility::Restricted(ref restricted) => Some(&restricted.path),
+            _ => None,
+        }
+    }
+
+    /// Constructs a `pub` visibility.
+    pub fn public() -> Self {
+        Visibility::Public(VisPublic {
+            pub_token: <Token![pub]>::default(),
+        })
+    }
+
+    /// Constructs the inherited (private) visibility.
+    pub fn inherited() -> Self {
+        Visibility::Inherited
+    }
+
+    /// Constructs a `pub(crate)` visibility.
+    pub fn crate_level() -> Self {
+        Visibility::Crate(VisCrate {
+            pub_token: <Token![pub]>::default(),
+            paren_token: token::Paren::default(),
+            crate_token: <Token![crate]>::default(),
+        })
+    }
+
+    /// Constructs a `pub(in path)` (or `pub(self)` / `pub(super)`)
+    /// visibility, inserting an `in` token automatically unless `path` is
+    /// the bare keyword `self`, `super`, or `crate`.
+    ///
+    /// A bare `crate` path prints identically to `Visibility::crate_level()`
+    /// and always reparses as `Visibility::Crate` (see the parser's first
+    /// `alt!` arm), so this returns `Visibility::crate_level()` directly
+    /// rather than a `Visibility::Restricted` that would disagree with
+    /// `is_crate_level()`/`restricted_path()` about its own meaning.
+    pub fn restricted(path: Path) -> Self {
+        if is_bare_keyword(&path, "crate") {
+            return Visibility::crate_level();
+        }
+        let in_token = if is_bare_path_keyword(&path) {
+            None
+        } else {
+            Some(<Token![in]>::default())
+        };
+        Visibility::Restricted(VisRestricted {
+            pub_token: <Token![pub]>::default(),
+            paren_token: token::Paren::default(),
+            in_token: in_token,
+            path: Box::new(path),
+        })
+    }
+}
+
+/// True if `path` is the single-segment path `keyword` (e.g. `self`).
+fn is_bare_keyword(path: &Path, keyword: &str) -> bool {
+    path.leading_colon.is_none() && path.segments.len() == 1
+        && path.segments.iter().next().unwrap().ident.to_string() == keyword
+}
+
+/// True if `path` is the bare keyword `self`, `super`, or `crate` -- the
+/// only paths which may appear after `pub(` without an `in` token. Printing
+/// (`to_tokens` for `VisRestricted`) and parsing (the `self`/`super` arms of
+/// `Synom for Visibility`) both rely on this same invariant.
+fn is_bare_path_keyword(path: &Path) -> bool {
+    is_bare_keyword(path, "self") || is_bare_keyword(path, "super") || is_bare_keyword(path, "crate")
+}
+
 #[cfg(feature = "parsing")]
 pub mod parsing {
     use super::*;
@@ -181,6 +367,7 @@ pub mod parsing {
                 }))
             )
             |
+            // See `is_bare_path_keyword` for why `self` needs no `in` token.
             do_parse!(
                 pub_token: keyword!(pub) >>
                 other: parens!(keyword!(self)) >>
@@ -297,11 +484,100 @@ mod printing {
         fn to_tokens(&self, tokens: &mut Tokens) {
             self.pub_token.to_tokens(tokens);
             self.paren_token.surround(tokens, |tokens| {
-                // XXX: If we have a path which is not "self" or "super",
-                // automatically add the "in" token.
-                self.in_token.to_tokens(tokens);
+                // See `is_bare_path_keyword`; synthesize `in` if needed so
+                // this always reparses back to an equivalent `Visibility`.
+                match self.in_token {
+                    Some(ref in_token) => in_token.to_tokens(tokens),
+                    None => if !is_bare_path_keyword(&self.path) {
+                        <Token![in]>::default().to_tokens(tokens);
+                    },
+                }
                 self.path.to_tokens(tokens);
             });
         }
     }
 }
+
+#[cfg(test)]
+#[cfg(all(feature = "parsing", feature = "printing"))]
+mod tests {
+    use super::*;
+
+    fn print(vis: &Visibility) -> String {
+        let mut tokens = ::quote::Tokens::new();
+        vis.to_tokens(&mut tokens);
+        tokens.to_string()
+    }
+
+    #[test]
+    fn restricted_visibility_round_trips() {
+        for input in &["pub(self)", "pub(super)", "pub(crate)", "pub(in some::module)"] {
+            let parsed: Visibility = ::parse_str(input).unwrap();
+            let printed = print(&parsed);
+            let reparsed: Visibility = ::parse_str(&printed).unwrap();
+            assert_eq!(print(&reparsed), printed, "{} did not round-trip", input);
+        }
+    }
+
+    #[test]
+    fn restricted_crate_path_is_crate_level() {
+        let path: Path = ::parse_str("crate").unwrap();
+        let vis = Visibility::restricted(path);
+        assert!(vis.is_crate_level());
+        assert!(vis.restricted_path().is_none());
+        assert_eq!(print(&vis), print(&Visibility::crate_level()));
+    }
+
+    #[test]
+    fn restricted_inserts_in_token_for_multi_segment_path() {
+        let path: Path = ::parse_str("some::module").unwrap();
+        let printed = print(&Visibility::restricted(path));
+        // `restricted` sets `in_token` itself for a multi-segment path, so
+        // the printed form is `pub(in some::module)` and reparses.
+        let _reparsed: Visibility = ::parse_str(&printed).unwrap();
+        assert!(printed.contains("in"));
+    }
+
+    fn field_idents(fields: &Fields) -> Vec<String> {
+        fields
+            .iter()
+            .map(|field| field.ident.as_ref().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn fields_named_iterates_in_order() {
+        let named: FieldsNamed = ::parse_str("{ a: T, b: U }").unwrap();
+        let fields = Fields::Named(named);
+        assert_eq!(fields.len(), 2);
+        assert!(!fields.is_empty());
+        assert_eq!(field_idents(&fields), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn fields_unnamed_iterates() {
+        let unnamed: FieldsUnnamed = ::parse_str("(T, U)").unwrap();
+        let fields = Fields::Unnamed(unnamed);
+        assert_eq!(fields.len(), 2);
+        assert!(!fields.is_empty());
+        assert_eq!(fields.iter().count(), 2);
+    }
+
+    #[test]
+    fn fields_unit_iterates_empty() {
+        let fields = Fields::Unit;
+        assert_eq!(fields.len(), 0);
+        assert!(fields.is_empty());
+        assert_eq!(fields.iter().count(), 0);
+    }
+
+    #[test]
+    fn fields_iter_mut_sees_every_field() {
+        let named: FieldsNamed = ::parse_str("{ a: T, b: U }").unwrap();
+        let mut fields = Fields::Named(named);
+        for field in fields.iter_mut() {
+            field.vis = Visibility::crate_level();
+        }
+        assert!(fields.iter().all(|field| field.vis.is_crate_level()));
+    }
+}